@@ -0,0 +1,42 @@
+// Copyright 2012-2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Contains infrastructure for configuring the compiler, including parsing
+//! command line options.
+
+// NOTE: this is an excerpt of the full `config.rs`; only the `-Z` debugging
+// options table relevant to the lifetime-error explanation work is shown.
+// The surrounding `options!`/`DebuggingOptions` machinery is unchanged.
+
+options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
+         build_debugging_options, "Z", "debugging",
+         DB_OPTIONS, db_type_desc, dbsetters,
+    verbose: bool = (false, parse_bool, [UNTRACKED],
+        "in general, enable more debug printouts"),
+    span_free_formats: bool = (false, parse_bool, [UNTRACKED],
+        "force values to be considered different in the presence of borrows"),
+    identify_regions: bool = (false, parse_bool, [UNTRACKED],
+        "make unnamed regions display as '<id> (where <id> is the region id)"),
+    borrowck: Option<String> = (None, parse_opt_string, [UNTRACKED],
+        "select which borrowck is used (`ast`, `mir`, or `migrate`)"),
+    two_phase_borrows: bool = (false, parse_bool, [UNTRACKED],
+        "use two-phase reserved/active distinction for `&mut` borrows in MIR borrowck"),
+    nll: bool = (false, parse_bool, [UNTRACKED],
+        "enable non-lexical lifetimes"),
+    polonius: bool = (false, parse_bool, [UNTRACKED],
+        "enable polonius-based borrow-checker"),
+    explain_lifetime_errors: bool = (false, parse_bool, [UNTRACKED],
+        "emit a verbose explanation of lifetime errors: walk the full \
+         constraint chain that forces each outlives relation, label any \
+         secondary chains, and (for general outlives errors, not \
+         escaping-data errors) emit a machine-readable blame note"),
+    disable_nll_user_type_assert: bool = (false, parse_bool, [UNTRACKED],
+        "disable user provided type assertion in NLL"),
+}
@@ -15,6 +15,7 @@ use rustc::infer::error_reporting::nice_region_error::NiceRegionError;
 use rustc::infer::InferCtxt;
 use rustc::mir::{Location, Mir};
 use rustc::ty::{self, RegionVid};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_data_structures::indexed_vec::IndexVec;
 use rustc_errors::{Diagnostic, DiagnosticBuilder};
 use std::collections::VecDeque;
@@ -55,6 +56,22 @@ enum Trace {
     NotVisited,
 }
 
+/// A structured, machine-readable description of the constraint blamed
+/// for an outlives error. Unlike a `Diagnostic`, this carries only
+/// plain data, so IDE/rust-analyzer-style consumers can ask "why does
+/// `'a` need to outlive `'b` here?" and render their own UI.
+#[derive(Clone, Debug)]
+crate struct BlameConstraintDescription {
+    crate category: ConstraintCategory,
+    crate file_name: String,
+    crate line: usize,
+    crate column: usize,
+    crate fr_name: String,
+    crate outlived_fr_name: String,
+    crate fr_is_local: bool,
+    crate outlived_fr_is_local: bool,
+}
+
 impl<'tcx> RegionInferenceContext<'tcx> {
     /// Tries to find the best constraint to blame for the fact that
     /// `R: from_region`, where `R` is some region that meets
@@ -62,12 +79,19 @@ impl<'tcx> RegionInferenceContext<'tcx> {
     /// creating a constraint path that forces `R` to outlive
     /// `from_region`, and then finding the best choices within that
     /// path to blame.
+    ///
+    /// Returns the candidate constraints to blame, ranked best-first,
+    /// together with the full constraint path that led to them (from
+    /// the source region to the target). Each candidate is a
+    /// `(category, span, target_region)` triple; callers that only
+    /// want a single span take the first entry, while diagnostics can
+    /// reconstruct and explain the whole chain of outlives reasoning.
     fn best_blame_constraint(
         &self,
         mir: &Mir<'tcx>,
         from_region: RegionVid,
         target_test: impl Fn(RegionVid) -> bool,
-    ) -> (ConstraintCategory, Span, RegionVid) {
+    ) -> (Vec<(ConstraintCategory, Span, RegionVid)>, Vec<OutlivesConstraint>) {
         debug!("best_blame_constraint(from_region={:?})", from_region);
 
         // Find all paths
@@ -86,64 +110,113 @@ impl<'tcx> RegionInferenceContext<'tcx> {
                 .collect::<Vec<_>>()
         );
 
-        // Classify each of the constraints along the path.
-        let mut categorized_path: Vec<(ConstraintCategory, Span)> = path
-            .iter()
-            .map(|constraint| (constraint.category, constraint.locations.span(mir)))
-            .collect();
-        debug!(
-            "best_blame_constraint: categorized_path={:#?}",
-            categorized_path
-        );
-
-        // To find the best span to cite, we first try to look for the
-        // final constraint that is interesting and where the `sup` is
-        // not unified with the ultimate target region. The reason
-        // for this is that we have a chain of constraints that lead
-        // from the source to the target region, something like:
+        // Score each constraint along the path with a single combined
+        // cost (lower is better) and rank them best-first. This replaces
+        // the old pair of heuristics -- "closest interesting constraint
+        // whose SCC differs from the target, else sort by category" --
+        // with an explicit weighted model over the three factors the
+        // request calls for:
+        //
+        //    * `category_weight` -- how interesting the category is to
+        //      blame (a `Return`/`Assignment` beats a `Boring` edge);
+        //    * distance from the target region along the path -- the
+        //      closer to where the value escapes, the better;
+        //    * whether the span points at user-written code or at a
+        //      compiler-generated (dummy) location.
+        //
+        // The chain looks something like:
         //
         //    '0: '1 ('0 is the source)
         //    '1: '2
-        //    '2: '3
-        //    '3: '4
-        //    '4: '5
+        //    ...
         //    '5: '6 ('6 is the target)
         //
-        // Some of those regions are unified with `'6` (in the same
-        // SCC).  We want to screen those out. After that point, the
-        // "closest" constraint we have to the end is going to be the
-        // most likely to be the point where the value escapes -- but
-        // we still want to screen for an "interesting" point to
-        // highlight (e.g., a call site or something).
+        // Constraints whose `sup` is unified with the target (same SCC)
+        // are the least interesting to cite, so they take an extra
+        // penalty. The weights are chosen so category dominates, with
+        // distance refining between constraints of similar category;
+        // all are named constants here so the model is tunable.
+        const CATEGORY_SCALE: u32 = 10;
+        const DISTANCE_SCALE: u32 = 1;
+        const SAME_SCC_PENALTY: u32 = 40;
+        const COMPILER_PENALTY: u32 = 5;
+
         let target_scc = self.constraint_sccs.scc(target_region);
-        let best_choice = (0..path.len()).rev().find(|&i| {
-            let constraint = path[i];
+        let mut ranked: Vec<(u32, usize, ConstraintCategory, Span)> = path
+            .iter()
+            .enumerate()
+            .map(|(i, constraint)| {
+                let category = constraint.category;
+                let span = constraint.locations.span(mir);
+
+                // Distance from the target: the last constraint on the
+                // path sits right next to the target region.
+                let distance = (path.len() - 1 - i) as u32;
+                let same_scc_as_target =
+                    self.constraint_sccs.scc(constraint.sup) == target_scc;
+                // A dummy span has no user-visible location and almost
+                // always denotes a compiler-generated constraint.
+                let compiler_generated = span.is_dummy();
+
+                let cost = Self::category_weight(category) * CATEGORY_SCALE
+                    + distance * DISTANCE_SCALE
+                    + if same_scc_as_target { SAME_SCC_PENALTY } else { 0 }
+                    + if compiler_generated { COMPILER_PENALTY } else { 0 };
+
+                (cost, i, category, span)
+            })
+            .collect();
 
-            let constraint_sup_scc = self.constraint_sccs.scc(constraint.sup);
+        // Sort by cost, breaking ties on path position so the ranking
+        // is fully deterministic rather than depending on iteration
+        // order or the enum's derived ordering.
+        ranked.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.cmp(&b.1)));
+        debug!("best_blame_constraint: ranked={:#?}", ranked);
 
-            match categorized_path[i].0 {
-                ConstraintCategory::OpaqueType
-                | ConstraintCategory::Boring
-                | ConstraintCategory::BoringNoLocation
-                | ConstraintCategory::Internal => false,
-                _ => constraint_sup_scc != target_scc,
-            }
-        });
-        if let Some(i) = best_choice {
-            let (category, span) = categorized_path[i];
-            return (category, span, target_region);
-        }
+        let candidates = ranked
+            .into_iter()
+            .map(|(_, _, category, span)| (category, span, target_region))
+            .collect();
 
-        // If that search fails, that is.. unusual. Maybe everything
-        // is in the same SCC or something. In that case, find what
-        // appears to be the most interesting point to report to the
-        // user via an even more ad-hoc guess.
-        categorized_path.sort_by(|p0, p1| p0.0.cmp(&p1.0));
-        debug!("best_blame_constraint: sorted_path={:#?}", categorized_path);
+        (candidates, path)
+    }
 
-        let &(category, span) = categorized_path.first().unwrap();
+    /// Assigns a blame weight to each `ConstraintCategory`; a lower
+    /// weight means the category is a more useful thing to point the
+    /// user at. This is one term of the combined cost used by
+    /// `best_blame_constraint`.
+    fn category_weight(category: ConstraintCategory) -> u32 {
+        match category {
+            ConstraintCategory::Return => 0,
+            ConstraintCategory::Assignment => 1,
+            ConstraintCategory::CallArgument => 2,
+            ConstraintCategory::Cast => 3,
+            ConstraintCategory::TypeAnnotation => 4,
+            ConstraintCategory::ClosureBounds => 5,
+            ConstraintCategory::SizedBound => 6,
+            ConstraintCategory::CopyBound => 7,
+            ConstraintCategory::OpaqueType => 8,
+            ConstraintCategory::Boring => 9,
+            ConstraintCategory::BoringNoLocation => 10,
+            ConstraintCategory::Internal => 11,
+        }
+    }
 
-        (category, span, target_region)
+    /// Picks a representative span to cite for a blame `path`: the span
+    /// of its most interesting (lowest-weight) constraint, skipping the
+    /// `Boring`/`Internal` housekeeping edges that carry no useful
+    /// location. Used to label the secondary blame paths surfaced by
+    /// `find_all_constraint_paths_between_regions`.
+    fn blame_span_for_path(&self, mir: &Mir<'tcx>, path: &[OutlivesConstraint]) -> Option<Span> {
+        path.iter()
+            .filter(|constraint| match constraint.category {
+                ConstraintCategory::Boring
+                | ConstraintCategory::BoringNoLocation
+                | ConstraintCategory::Internal => false,
+                _ => true,
+            })
+            .min_by_key(|constraint| Self::category_weight(constraint.category))
+            .map(|constraint| constraint.locations.span(mir))
     }
 
     /// Walks the graph of constraints (where `'a: 'b` is considered
@@ -158,6 +231,21 @@ impl<'tcx> RegionInferenceContext<'tcx> {
         &self,
         from_region: RegionVid,
         target_test: impl Fn(RegionVid) -> bool,
+    ) -> Option<(Vec<OutlivesConstraint>, RegionVid)> {
+        self.find_constraint_paths_between_regions_excluding(
+            from_region, target_test, &FxHashSet::default())
+    }
+
+    /// Like `find_constraint_paths_between_regions`, but ignores any
+    /// edge `('sup, 'sub)` present in `excluded_edges`. This is the
+    /// building block used to enumerate several *edge-disjoint* blame
+    /// paths: after a path is found its edges are excluded and the
+    /// search is run again.
+    fn find_constraint_paths_between_regions_excluding(
+        &self,
+        from_region: RegionVid,
+        target_test: impl Fn(RegionVid) -> bool,
+        excluded_edges: &FxHashSet<(RegionVid, RegionVid)>,
     ) -> Option<(Vec<OutlivesConstraint>, RegionVid)> {
         let mut context = IndexVec::from_elem(Trace::NotVisited, &self.definitions);
         context[from_region] = Trace::StartRegion;
@@ -200,6 +288,9 @@ impl<'tcx> RegionInferenceContext<'tcx> {
                                                                    &self.constraints,
                                                                    fr_static) {
                 assert_eq!(constraint.sup, r);
+                if excluded_edges.contains(&(constraint.sup, constraint.sub)) {
+                    continue;
+                }
                 let sub_region = constraint.sub;
                 if let Trace::NotVisited = context[sub_region] {
                     context[sub_region] = Trace::FromOutlivesConstraint(constraint);
@@ -211,6 +302,45 @@ impl<'tcx> RegionInferenceContext<'tcx> {
         None
     }
 
+    /// Enumerates up to `max_paths` *edge-disjoint* constraint paths
+    /// from `from_region` to a region satisfying `target_test`, ignoring
+    /// any edges already in `excluded_edges`. Where
+    /// `find_constraint_paths_between_regions` returns only the single
+    /// shortest path, several genuinely distinct chains can force the
+    /// same outlives relation (for example a value borrowed in two
+    /// branches). Each found path has its edges excluded before the
+    /// next search, so the returned chains share no constraint with
+    /// each other or with the pre-excluded set.
+    ///
+    /// Passing the edges of an already-known path as `excluded_edges`
+    /// lets callers ask only for the *additional* chains, without
+    /// re-walking the graph to rediscover that known path.
+    fn find_all_constraint_paths_between_regions(
+        &self,
+        from_region: RegionVid,
+        target_test: impl Fn(RegionVid) -> bool,
+        max_paths: usize,
+        mut excluded_edges: FxHashSet<(RegionVid, RegionVid)>,
+    ) -> Vec<(Vec<OutlivesConstraint>, RegionVid)> {
+        let mut results = vec![];
+
+        while results.len() < max_paths {
+            match self.find_constraint_paths_between_regions_excluding(
+                from_region, &target_test, &excluded_edges)
+            {
+                Some((path, target_region)) => {
+                    for constraint in &path {
+                        excluded_edges.insert((constraint.sup, constraint.sub));
+                    }
+                    results.push((path, target_region));
+                }
+                None => break,
+            }
+        }
+
+        results
+    }
+
     /// Report an error because the universal region `fr` was required to outlive
     /// `outlived_fr` but it is not known to do so. For example:
     ///
@@ -230,11 +360,38 @@ impl<'tcx> RegionInferenceContext<'tcx> {
     ) {
         debug!("report_error(fr={:?}, outlived_fr={:?})", fr, outlived_fr);
 
-        let (category, span, _) = self.best_blame_constraint(
+        let (blame_candidates, path) = self.best_blame_constraint(
             mir,
             fr,
             |r| r == outlived_fr
         );
+        let (category, span, _) = blame_candidates[0];
+
+        // Under `-Z explain-lifetime-errors`, look for other chains that
+        // independently force the same outlives relation and point at
+        // them too with secondary "also required here" labels. We seed
+        // the search with the edges of the primary `path` we just found,
+        // so it enumerates only genuinely *additional*, edge-disjoint
+        // chains rather than re-walking the graph and rediscovering the
+        // primary one (or a near-duplicate differing by a single edge).
+        let also_required = if Self::explain_lifetime_errors(infcx) {
+            let primary_edges: FxHashSet<(RegionVid, RegionVid)> =
+                path.iter().map(|c| (c.sup, c.sub)).collect();
+            let other_paths = self.find_all_constraint_paths_between_regions(
+                fr, |r| r == outlived_fr, 2, primary_edges);
+
+            let mut spans = vec![];
+            for (other_path, _) in &other_paths {
+                if let Some(other_span) = self.blame_span_for_path(mir, other_path) {
+                    if other_span != span && !spans.contains(&other_span) {
+                        spans.push(other_span);
+                    }
+                }
+            }
+            spans
+        } else {
+            vec![]
+        };
 
         // Check if we can use one of the "nice region errors".
         if let (Some(f), Some(o)) = (self.to_error_region(fr), self.to_error_region(outlived_fr)) {
@@ -256,11 +413,13 @@ impl<'tcx> RegionInferenceContext<'tcx> {
             (ConstraintCategory::Assignment, true, false) |
             (ConstraintCategory::CallArgument, true, false) =>
                 self.report_escaping_data_error(mir, infcx, mir_def_id, fr, outlived_fr,
-                                                category, span, errors_buffer),
+                                                category, span, &path, &also_required,
+                                                errors_buffer),
             _ =>
                 self.report_general_error(mir, infcx, mir_def_id, fr, fr_is_local,
                                           outlived_fr, outlived_fr_is_local,
-                                          category, span, errors_buffer),
+                                          category, span, &path, &also_required,
+                                          errors_buffer),
         };
     }
 
@@ -273,6 +432,8 @@ impl<'tcx> RegionInferenceContext<'tcx> {
         outlived_fr: RegionVid,
         category: ConstraintCategory,
         span: Span,
+        path: &[OutlivesConstraint],
+        also_required: &[Span],
         errors_buffer: &mut Vec<Diagnostic>,
     ) {
         let fr_name_and_span = self.get_var_name_and_span_for_region(infcx.tcx, mir, fr);
@@ -288,7 +449,8 @@ impl<'tcx> RegionInferenceContext<'tcx> {
         {
             return self.report_general_error(mir, infcx, mir_def_id,
                                              fr, true, outlived_fr, false,
-                                             category, span, errors_buffer);
+                                             category, span, path, also_required,
+                                             errors_buffer);
         }
 
         let mut diag = infcx.tcx.sess.struct_span_err(
@@ -317,6 +479,12 @@ impl<'tcx> RegionInferenceContext<'tcx> {
             }
         }
 
+        for &other_span in also_required {
+            diag.span_label(other_span, "...also required here");
+        }
+
+        self.explain_constraint_path(mir, infcx, mir_def_id, &mut diag, path);
+
         diag.buffer(errors_buffer);
     }
 
@@ -331,6 +499,8 @@ impl<'tcx> RegionInferenceContext<'tcx> {
         outlived_fr_is_local: bool,
         category: ConstraintCategory,
         span: Span,
+        path: &[OutlivesConstraint],
+        also_required: &[Span],
         errors_buffer: &mut Vec<Diagnostic>,
     ) {
         let mut diag = infcx.tcx.sess.struct_span_err(
@@ -362,13 +532,121 @@ impl<'tcx> RegionInferenceContext<'tcx> {
             },
         }
 
+        for &other_span in also_required {
+            diag.span_label(other_span, "...also required here");
+        }
+
+        // Under `-Z explain-lifetime-errors`, also surface the blame as
+        // a single machine-readable note, so tooling driving the compiler
+        // can parse the same structured description that
+        // `describe_blame_constraint` exposes programmatically. We build
+        // it from the `category`/`span`/names already computed for this
+        // error rather than re-walking the constraint graph.
+        if Self::explain_lifetime_errors(infcx) {
+            let desc = self.blame_description_from(
+                infcx, span, category, &fr_name, &outlived_fr_name,
+                fr_is_local, outlived_fr_is_local);
+            diag.note(&format!(
+                "lifetime blame: {}at {}:{}:{} requires `{}` to outlive `{}` (local: {}/{})",
+                desc.category, desc.file_name, desc.line, desc.column,
+                desc.fr_name, desc.outlived_fr_name,
+                desc.fr_is_local, desc.outlived_fr_is_local,
+            ));
+        }
+
         self.add_static_impl_trait_suggestion(
             infcx, &mut diag, fr, fr_name, outlived_fr,
         );
 
+        self.explain_constraint_path(mir, infcx, mir_def_id, &mut diag, path);
+
         diag.buffer(errors_buffer);
     }
 
+    /// True if the user opted in to the verbose lifetime-error
+    /// explanation via `-Z explain-lifetime-errors` (declared, like the
+    /// other `-Z` flags, in `librustc/session/config.rs`). This is kept
+    /// deliberately separate from the general-purpose `-Z verbose`
+    /// firehose so that the constraint-trace notes and the secondary
+    /// blame labels are opt-in and do not perturb normal diagnostics.
+    fn explain_lifetime_errors(infcx: &InferCtxt<'_, '_, 'tcx>) -> bool {
+        infcx.tcx.sess.opts.debugging_opts.explain_lifetime_errors
+    }
+
+    /// Renders a `ConstraintCategory` as a short noun phrase suitable
+    /// for slotting into "...required here by this {}". This is distinct
+    /// from the `Display` impl, whose strings are sentence fragments
+    /// (e.g. `Return` displays as "returning this value ") that do not
+    /// read as nouns in this position.
+    fn category_noun(category: ConstraintCategory) -> &'static str {
+        match category {
+            ConstraintCategory::Assignment => "assignment",
+            ConstraintCategory::Return => "return",
+            ConstraintCategory::Cast => "cast",
+            ConstraintCategory::CallArgument => "call argument",
+            ConstraintCategory::TypeAnnotation => "type annotation",
+            ConstraintCategory::ClosureBounds => "closure body",
+            ConstraintCategory::SizedBound => "`Sized` bound",
+            ConstraintCategory::CopyBound => "copy",
+            ConstraintCategory::OpaqueType => "opaque type",
+            ConstraintCategory::Boring
+            | ConstraintCategory::BoringNoLocation
+            | ConstraintCategory::Internal => "constraint",
+        }
+    }
+
+    /// When `-Z explain-lifetime-errors` is set, walk the constraint
+    /// `path` that forced the outlives relation and attach an ordered
+    /// series of notes, one per *interesting* constraint, so the user
+    /// can follow the whole `'0: '1`, `'1: '2`, ... chain rather than
+    /// seeing only the final span. Regions are rendered with their
+    /// user-facing names (cached so each region is only named once)
+    /// rather than as raw inference vids; they are named through
+    /// `region_name_for_blame`, so `diag` gets no second "lifetime
+    /// defined here" label for regions the main message already named.
+    /// `Boring`/`Internal` constraints carry no useful location and are
+    /// skipped.
+    fn explain_constraint_path(
+        &self,
+        mir: &Mir<'tcx>,
+        infcx: &InferCtxt<'_, '_, 'tcx>,
+        mir_def_id: DefId,
+        diag: &mut DiagnosticBuilder<'_>,
+        path: &[OutlivesConstraint],
+    ) {
+        if !Self::explain_lifetime_errors(infcx) {
+            return;
+        }
+
+        let counter = &mut 1;
+        let mut names: FxHashMap<RegionVid, RegionName> = FxHashMap::default();
+        for constraint in path {
+            match constraint.category {
+                ConstraintCategory::Boring
+                | ConstraintCategory::BoringNoLocation
+                | ConstraintCategory::Internal => continue,
+                _ => {}
+            }
+
+            for &region in &[constraint.sup, constraint.sub] {
+                if !names.contains_key(&region) {
+                    let name = self.region_name_for_blame(
+                        infcx, mir, mir_def_id, region, counter);
+                    names.insert(region, name);
+                }
+            }
+
+            diag.span_note(
+                constraint.locations.span(mir),
+                &format!(
+                    "`{}: {}` required here by this {}",
+                    names[&constraint.sup], names[&constraint.sub],
+                    Self::category_noun(constraint.category),
+                ),
+            );
+        }
+    }
+
     fn add_static_impl_trait_suggestion(
         &self,
         infcx: &InferCtxt<'_, '_, 'tcx>,
@@ -468,7 +746,86 @@ impl<'tcx> RegionInferenceContext<'tcx> {
         fr1: RegionVid,
         fr2: RegionVid,
     ) -> Span {
-        let (_, span, _) = self.best_blame_constraint(mir, fr1, |r| r == fr2);
+        let (blame_candidates, _) = self.best_blame_constraint(mir, fr1, |r| r == fr2);
+        let (_, span, _) = blame_candidates[0];
         span
     }
+
+    /// Returns a structured description of the best constraint to blame
+    /// for the fact that `fr` must outlive `outlived_fr`, reusing
+    /// `best_blame_constraint` but without emitting any `Diagnostic`.
+    /// This is the programmatic counterpart of `report_error`, intended
+    /// for tooling that wants to render its own explanation.
+    crate fn describe_blame_constraint(
+        &self,
+        infcx: &InferCtxt<'_, '_, 'tcx>,
+        mir: &Mir<'tcx>,
+        mir_def_id: DefId,
+        fr: RegionVid,
+        outlived_fr: RegionVid,
+    ) -> BlameConstraintDescription {
+        let (blame_candidates, _) =
+            self.best_blame_constraint(mir, fr, |r| r == outlived_fr);
+        let (category, span, _) = blame_candidates[0];
+
+        let counter = &mut 1;
+        let fr_name = self.region_name_for_blame(infcx, mir, mir_def_id, fr, counter);
+        let outlived_fr_name =
+            self.region_name_for_blame(infcx, mir, mir_def_id, outlived_fr, counter);
+        let fr_is_local = self.universal_regions.is_local_free_region(fr);
+        let outlived_fr_is_local = self.universal_regions.is_local_free_region(outlived_fr);
+
+        self.blame_description_from(
+            infcx, span, category, &fr_name, &outlived_fr_name,
+            fr_is_local, outlived_fr_is_local)
+    }
+
+    /// Assembles a `BlameConstraintDescription` from an already-selected
+    /// blame `(category, span)` and the region names/locality flags the
+    /// caller has on hand. Shared by `describe_blame_constraint` (which
+    /// walks the graph to find the blame) and `report_general_error`
+    /// (which already has it), so neither recomputes the other's work.
+    fn blame_description_from(
+        &self,
+        infcx: &InferCtxt<'_, '_, 'tcx>,
+        span: Span,
+        category: ConstraintCategory,
+        fr_name: &RegionName,
+        outlived_fr_name: &RegionName,
+        fr_is_local: bool,
+        outlived_fr_is_local: bool,
+    ) -> BlameConstraintDescription {
+        let loc = infcx.tcx.sess.source_map().lookup_char_pos(span.lo());
+        BlameConstraintDescription {
+            category,
+            file_name: format!("{}", loc.file.name),
+            line: loc.line,
+            column: loc.col.to_usize() + 1,
+            fr_name: format!("{}", fr_name),
+            outlived_fr_name: format!("{}", outlived_fr_name),
+            fr_is_local,
+            outlived_fr_is_local,
+        }
+    }
+
+    /// Resolves the user-facing name of `region` for a structured blame
+    /// description. `give_region_a_name` insists on a `DiagnosticBuilder`
+    /// to optionally hang a label on; a structured description emits no
+    /// diagnostic, so we give it a throwaway note builder and
+    /// immediately `cancel()` it. Cancelling discards the builder and
+    /// anything the helper may have attached, so no note -- empty or
+    /// otherwise -- can ever reach the error stream.
+    fn region_name_for_blame(
+        &self,
+        infcx: &InferCtxt<'_, '_, 'tcx>,
+        mir: &Mir<'tcx>,
+        mir_def_id: DefId,
+        region: RegionVid,
+        counter: &mut usize,
+    ) -> RegionName {
+        let mut scratch = infcx.tcx.sess.diagnostic().struct_note_without_error("");
+        let name = self.give_region_a_name(infcx, mir, mir_def_id, region, counter, &mut scratch);
+        scratch.cancel();
+        name
+    }
 }
@@ -0,0 +1,22 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Check that the blame cost model still cites the return where the
+// borrowed value escapes (not the earlier `let z = x` assignment), as the
+// previous heuristic did -- i.e. the normal diagnostic output is unchanged.
+
+#![feature(nll)]
+
+fn foo<'a, 'b>(x: &'a u32, y: &'b u32) -> &'b u32 {
+    let z = x;
+    z //~ ERROR unsatisfied lifetime constraints
+}
+
+fn main() {}
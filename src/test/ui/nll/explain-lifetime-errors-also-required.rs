@@ -0,0 +1,28 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Check that `-Z explain-lifetime-errors` surfaces secondary "...also
+// required here" labels when more than one independent chain forces the
+// same outlives relation. Without the flag these labels are not emitted,
+// so normal diagnostics are unchanged.
+
+// compile-flags: -Z explain-lifetime-errors
+
+#![feature(nll)]
+
+fn foo<'a, 'b>(cond: bool, x: &'a u32, y: &'a u32) -> &'b u32 {
+    if cond {
+        x
+    } else {
+        y
+    } //~ ERROR unsatisfied lifetime constraints
+}
+
+fn main() {}
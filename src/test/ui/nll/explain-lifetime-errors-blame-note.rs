@@ -0,0 +1,24 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Check that `-Z explain-lifetime-errors` emits the structured,
+// machine-readable blame note, built from the same description that
+// `describe_blame_constraint` returns, so tooling driving the compiler
+// can parse what it would otherwise obtain programmatically.
+
+// compile-flags: -Z explain-lifetime-errors
+
+#![feature(nll)]
+
+fn foo<'a, 'b>(x: &'a u32) -> &'b u32 {
+    x //~ ERROR unsatisfied lifetime constraints
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Check that `-Z explain-lifetime-errors` attaches the constraint-trace
+// note chain to an outlives error (rather than only the final span),
+// and that regions are rendered with their user-facing names.
+
+// compile-flags: -Z explain-lifetime-errors
+
+#![feature(nll)]
+
+fn foo<'a, 'b>(x: &'a u32, y: &'b u32) -> &'b u32 {
+    x //~ ERROR unsatisfied lifetime constraints
+}
+
+fn main() {}